@@ -1,8 +1,14 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::io;
 use std::fs;
-use std::path::PathBuf;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
@@ -11,14 +17,19 @@ use ratatui::{
     style::{
         Color, Style, Stylize,
     },
-    text::Line,
+    text::{Line, Span},
     widgets::{
-        Block, HighlightSpacing, List, ListItem, ListState, Paragraph,
+        Block, Clear, Gauge, HighlightSpacing, List, ListItem, ListState, Paragraph,
         StatefulWidget, Widget,
     },
     DefaultTerminal,
     Frame
 };
+use serde::{Deserialize, Serialize};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
 
 fn main() -> io::Result<()> {
     let mut terminal = ratatui::init();
@@ -42,15 +53,308 @@ impl Default for Config {
     }
 }
 
+/// On-disk shape of the bookmarks file: TOML keys must be strings, so a
+/// bookmark's single-char key is stored as a one-character string.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BookmarksFile {
+    #[serde(default)]
+    entries: HashMap<String, PathBuf>,
+}
+
+/// Saved jump points, keyed by the character the user pressed to set them.
+#[derive(Debug, Default)]
+pub struct Bookmarks {
+    entries: HashMap<char, PathBuf>,
+}
+
+impl Bookmarks {
+    fn config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("wtw").join("bookmarks.toml"))
+    }
+
+    fn load() -> Self {
+        let Some(path) = Self::config_path() else {
+            return Self::default();
+        };
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        let Ok(file) = toml::from_str::<BookmarksFile>(&contents) else {
+            return Self::default();
+        };
+        let entries = file
+            .entries
+            .into_iter()
+            .filter_map(|(key, path)| key.chars().next().map(|c| (c, path)))
+            .collect();
+        Self { entries }
+    }
+
+    fn save(&self) {
+        let Some(path) = Self::config_path() else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let file = BookmarksFile {
+            entries: self
+                .entries
+                .iter()
+                .map(|(c, path)| (c.to_string(), path.clone()))
+                .collect(),
+        };
+        if let Ok(contents) = toml::to_string_pretty(&file) {
+            let _ = fs::write(&path, contents);
+        }
+    }
+
+    fn set(&mut self, key: char, path: PathBuf) {
+        self.entries.insert(key, path);
+        self.save();
+    }
+
+    fn get(&self, key: char) -> Option<&PathBuf> {
+        self.entries.get(&key)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&char, &PathBuf)> {
+        self.entries.iter()
+    }
+}
+
+/// Status of a background copy job as reported by its worker thread.
+#[derive(Debug, Clone)]
+pub enum CopyStatus {
+    Queued,
+    Running,
+    Done,
+    Failed(String),
+}
+
+/// A single export/import transfer tracked in the jobs pane.
 #[derive(Debug)]
+pub struct CopyJob {
+    source: PathBuf,
+    dest: PathBuf,
+    bytes_copied: u64,
+    total_bytes: u64,
+    status: CopyStatus,
+}
+
+impl CopyJob {
+    fn new(source: PathBuf, dest: PathBuf) -> Self {
+        Self {
+            source,
+            dest,
+            bytes_copied: 0,
+            total_bytes: 0,
+            status: CopyStatus::Queued,
+        }
+    }
+
+    fn progress_ratio(&self) -> f64 {
+        if self.total_bytes == 0 {
+            0.0
+        } else {
+            (self.bytes_copied as f64 / self.total_bytes as f64).min(1.0)
+        }
+    }
+
+    fn label(&self) -> String {
+        let name = self
+            .source
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.source.display().to_string());
+        let dest = self.dest.display();
+        match &self.status {
+            CopyStatus::Queued => format!("{name} -> {dest} (queued)"),
+            CopyStatus::Running => format!("{name} -> {dest} ({:.0}%)", self.progress_ratio() * 100.0),
+            CopyStatus::Done => format!("{name} -> {dest} (done)"),
+            CopyStatus::Failed(err) => format!("{name} -> {dest} (failed: {err})"),
+        }
+    }
+}
+
+/// Progress update sent from a copy worker thread back to the main loop.
+enum JobUpdate {
+    Total(usize, u64),
+    Progress(usize, u64),
+    Done(usize),
+    Failed(usize, String),
+}
+
+/// Recursively walks `path`, summing the size of every regular file.
+fn dir_size(path: &Path) -> u64 {
+    let metadata = match fs::metadata(path) {
+        Ok(m) => m,
+        Err(_) => return 0,
+    };
+    if metadata.is_file() {
+        return metadata.len();
+    }
+    let entries = match fs::read_dir(path) {
+        Ok(e) => e,
+        Err(_) => return 0,
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| dir_size(&entry.path()))
+        .sum()
+}
+
+/// Recursively copies `source` to `dest` using `std::fs`, reporting bytes
+/// copied after every file through `tx`.
+fn copy_tree(job_id: usize, source: &Path, dest: &Path, tx: &Sender<JobUpdate>) -> io::Result<()> {
+    let metadata = fs::metadata(source)?;
+    if metadata.is_dir() {
+        fs::create_dir_all(dest)?;
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            copy_tree(job_id, &entry.path(), &dest.join(entry.file_name()), tx)?;
+        }
+    } else {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(source, dest)?;
+        let _ = tx.send(JobUpdate::Progress(job_id, metadata.len()));
+    }
+    Ok(())
+}
+
+/// Spawns a worker thread that copies `source` to `dest` and streams
+/// progress for job `job_id` over `tx`.
+fn spawn_copy_job(job_id: usize, source: PathBuf, dest: PathBuf, tx: Sender<JobUpdate>) {
+    thread::spawn(move || {
+        let total = dir_size(&source);
+        let _ = tx.send(JobUpdate::Total(job_id, total));
+        match copy_tree(job_id, &source, &dest, &tx) {
+            Ok(()) => {
+                let _ = tx.send(JobUpdate::Done(job_id));
+            }
+            Err(e) => {
+                let _ = tx.send(JobUpdate::Failed(job_id, e.to_string()));
+            }
+        }
+    });
+}
+
+/// Contents loaded for the preview pane, keyed by path in `App::preview_cache`.
+#[derive(Debug, Clone)]
+pub enum PreviewContent {
+    Loading,
+    Text(Vec<Line<'static>>),
+    Directory(Vec<String>),
+    Meta { size: u64, permissions: String },
+    Error(String),
+}
+
+/// Files larger than this are shown as a metadata summary instead of being
+/// read and highlighted.
+const MAX_PREVIEW_BYTES: u64 = 64 * 1024;
+
+fn format_permissions(metadata: &fs::Metadata) -> String {
+    use std::os::unix::fs::PermissionsExt;
+    format!("{:o}", metadata.permissions().mode() & 0o777)
+}
+
+static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+static THEME_SET: OnceLock<ThemeSet> = OnceLock::new();
+
+/// Highlights `text` using syntect, picking a syntax from `path`'s extension,
+/// and converts the styled spans into owned ratatui `Line`s. The syntax and
+/// theme dumps are parsed once and reused, since loading them is too slow to
+/// redo on every preview request.
+fn highlight_text(text: &str, path: &Path) -> Vec<Line<'static>> {
+    let syntax_set = SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines);
+    let theme_set = THEME_SET.get_or_init(ThemeSet::load_defaults);
+    let theme = &theme_set.themes["base16-ocean.dark"];
+
+    let syntax = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| syntax_set.find_syntax_by_extension(ext))
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    LinesWithEndings::from(text)
+        .map(|line| {
+            let ranges = highlighter.highlight_line(line, syntax_set).unwrap_or_default();
+            let spans = ranges
+                .into_iter()
+                .map(|(style, text)| {
+                    let color = Color::Rgb(style.foreground.r, style.foreground.g, style.foreground.b);
+                    Span::styled(text.trim_end_matches('\n').to_string(), Style::new().fg(color))
+                })
+                .collect::<Vec<_>>();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Reads and classifies whatever is at `path` for the preview pane: a short
+/// listing for directories, highlighted text for small text files, and a
+/// metadata summary for binary or too-large files.
+fn read_preview(path: &Path) -> PreviewContent {
+    let metadata = match fs::metadata(path) {
+        Ok(m) => m,
+        Err(e) => return PreviewContent::Error(e.to_string()),
+    };
+
+    if metadata.is_dir() {
+        let mut entries = fs::read_dir(path)
+            .map(|rd| {
+                rd.filter_map(|e| e.ok().map(|e| e.file_name().to_string_lossy().to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        entries.sort();
+        return PreviewContent::Directory(entries);
+    }
+
+    if metadata.len() > MAX_PREVIEW_BYTES {
+        return PreviewContent::Meta {
+            size: metadata.len(),
+            permissions: format_permissions(&metadata),
+        };
+    }
+
+    let bytes = match fs::read(path) {
+        Ok(b) => b,
+        Err(e) => return PreviewContent::Error(e.to_string()),
+    };
+
+    match std::str::from_utf8(&bytes) {
+        Ok(text) if !text.contains('\0') => PreviewContent::Text(highlight_text(text, path)),
+        _ => PreviewContent::Meta {
+            size: metadata.len(),
+            permissions: format_permissions(&metadata),
+        },
+    }
+}
+
+/// Spawns a worker thread that loads and highlights `path` for preview
+/// request `id`, sending the result back keyed by path so it can be cached.
+fn spawn_preview_job(id: u64, path: PathBuf, tx: Sender<(u64, PathBuf, PreviewContent)>) {
+    thread::spawn(move || {
+        let content = read_preview(&path);
+        let _ = tx.send((id, path, content));
+    });
+}
+
+#[derive(Debug, Clone)]
 pub struct FileItem {
     name: String,
     is_directory: bool,
+    size: u64,
+    modified: SystemTime,
 }
 
 impl FileItem {
-    fn new(name: String, is_directory: bool) -> Self {
-        Self { name, is_directory }
+    fn new(name: String, is_directory: bool, size: u64, modified: SystemTime) -> Self {
+        Self { name, is_directory, size, modified }
     }
 
     fn name(&self) -> &str {
@@ -60,29 +364,202 @@ impl FileItem {
     fn is_directory(&self) -> bool {
         self.is_directory
     }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn modified(&self) -> SystemTime {
+        self.modified
+    }
+
+    fn extension(&self) -> &str {
+        Path::new(&self.name).extension().and_then(|e| e.to_str()).unwrap_or("")
+    }
+}
+
+/// How a `FileList`'s entries are ordered; cycled with a key and shown in
+/// the pane title.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortMode {
+    #[default]
+    Name,
+    Size,
+    Mtime,
+    Extension,
+    DirsFirst,
+}
+
+impl SortMode {
+    fn next(self) -> Self {
+        match self {
+            SortMode::Name => SortMode::Size,
+            SortMode::Size => SortMode::Mtime,
+            SortMode::Mtime => SortMode::Extension,
+            SortMode::Extension => SortMode::DirsFirst,
+            SortMode::DirsFirst => SortMode::Name,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortMode::Name => "Name",
+            SortMode::Size => "Size",
+            SortMode::Mtime => "Modified",
+            SortMode::Extension => "Extension",
+            SortMode::DirsFirst => "Dirs first",
+        }
+    }
+}
+
+/// Scores how well `query` fuzzy-matches `name`: substring matches score by
+/// position (earlier is better), in-order subsequence matches score worse,
+/// and anything else doesn't match at all.
+fn fuzzy_score(name: &str, query: &str) -> Option<usize> {
+    if query.is_empty() {
+        return None;
+    }
+    let name_lower = name.to_lowercase();
+    let query_lower = query.to_lowercase();
+
+    if let Some(pos) = name_lower.find(&query_lower) {
+        return Some(pos);
+    }
+
+    let chars: Vec<char> = name_lower.chars().collect();
+    let mut first = None;
+    let mut last = 0;
+    let mut cursor = 0;
+    for qc in query_lower.chars() {
+        let idx = cursor + chars[cursor..].iter().position(|&c| c == qc)?;
+        first.get_or_insert(idx);
+        last = idx;
+        cursor = idx + 1;
+    }
+    // Always worse than any substring match, and narrower spans (tighter
+    // clusters of matched characters) score better among subsequence matches.
+    Some(usize::MAX / 2 + (last - first.unwrap_or(0)))
 }
 
-#[derive(Debug)]
 pub struct FileList {
     items: Vec<FileItem>,
+    all_items: Vec<FileItem>,
     state: ListState,
     current_path: PathBuf,
+    watcher: Option<RecommendedWatcher>,
+    watch_tx: Sender<Instant>,
+    watch_rx: Receiver<Instant>,
+    pending_since: Option<Instant>,
+    marked: HashSet<usize>,
+    sort_mode: SortMode,
+    filter: String,
 }
 
+impl fmt::Debug for FileList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileList")
+            .field("items", &self.items)
+            .field("state", &self.state)
+            .field("current_path", &self.current_path)
+            .field("sort_mode", &self.sort_mode)
+            .field("filter", &self.filter)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Events are debounced by this long before triggering a refresh, so a burst
+/// of writes from a copy job doesn't re-read the directory on every event.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
 impl FileList {
     fn new(items: Vec<String>, path: PathBuf) -> Self {
         let mut state = ListState::default();
         state.select(Some(0)); // Select first item by default
-        
+
         let file_items: Vec<FileItem> = items.into_iter().map(|name| {
-            let is_dir = fs::metadata(&path.join(&name)).map(|m| m.is_dir()).unwrap_or(false);
-            FileItem::new(name, is_dir)
+            let metadata = fs::metadata(path.join(&name)).ok();
+            let is_dir = metadata.as_ref().map(|m| m.is_dir()).unwrap_or(false);
+            let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
+            let modified = metadata
+                .as_ref()
+                .and_then(|m| m.modified().ok())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            FileItem::new(name, is_dir, size, modified)
         }).collect();
-        
-        Self {
-            items: file_items,
+
+        let (watch_tx, watch_rx) = mpsc::channel();
+
+        let mut list = Self {
+            items: file_items.clone(),
+            all_items: file_items,
             state,
             current_path: path,
+            watcher: None,
+            watch_tx,
+            watch_rx,
+            pending_since: None,
+            marked: HashSet::new(),
+            sort_mode: SortMode::default(),
+            filter: String::new(),
+        };
+        list.apply_sort();
+        list.apply_filter();
+        list.rewatch();
+        list
+    }
+
+    /// Tears down the watch on the previous directory (if any) and starts a
+    /// non-recursive watch on `current_path`.
+    fn rewatch(&mut self) {
+        let tx = self.watch_tx.clone();
+        let mut watcher = match notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok() {
+                let _ = tx.send(Instant::now());
+            }
+        }) {
+            Ok(watcher) => watcher,
+            Err(_) => {
+                self.watcher = None;
+                return;
+            }
+        };
+
+        self.watcher = match watcher.watch(&self.current_path, RecursiveMode::NonRecursive) {
+            Ok(()) => Some(watcher),
+            Err(_) => None,
+        };
+    }
+
+    /// Drains pending watch events and, once they've been quiet for
+    /// `WATCH_DEBOUNCE`, refreshes the listing while keeping the current
+    /// selection on the same named entry.
+    fn poll_watch(&mut self) {
+        while let Ok(at) = self.watch_rx.try_recv() {
+            self.pending_since = Some(at);
+        }
+        if let Some(since) = self.pending_since {
+            if since.elapsed() >= WATCH_DEBOUNCE {
+                self.refresh_items_preserving_selection();
+                self.pending_since = None;
+            }
+        }
+    }
+
+    /// Like `refresh_items`, but keeps the selection on the entry with the
+    /// same name instead of resetting to index 0.
+    fn refresh_items_preserving_selection(&mut self) {
+        let selected_name = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|item| item.name().to_string());
+
+        self.refresh_items();
+
+        if let Some(name) = selected_name {
+            if let Some(idx) = self.items.iter().position(|i| i.name() == name) {
+                self.state.select(Some(idx));
+            }
         }
     }
 
@@ -116,6 +593,7 @@ impl FileList {
                 if new_path.is_dir() {
                     self.current_path = new_path;
                     self.refresh_items();
+                    self.rewatch();
                     return true;
                 }
             }
@@ -127,6 +605,7 @@ impl FileList {
         if let Some(parent) = self.current_path.parent() {
             self.current_path = parent.to_path_buf();
             self.refresh_items();
+            self.rewatch();
             return true;
         }
         false
@@ -137,17 +616,155 @@ impl FileList {
             .unwrap_or_else(|_| fs::read_dir("/").unwrap())
             .filter_map(|entry| {
                 let entry = entry.ok()?;
-                let is_dir = entry.file_type().ok()?.is_dir();
-                Some(FileItem::new(entry.file_name().to_string_lossy().to_string(), is_dir))
+                let metadata = entry.metadata().ok()?;
+                Some(FileItem::new(
+                    entry.file_name().to_string_lossy().to_string(),
+                    metadata.is_dir(),
+                    metadata.len(),
+                    metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                ))
             })
             .collect::<Vec<FileItem>>();
-        
+
         // Add ".." at the beginning for navigation
-        items.insert(0, FileItem::new("..".to_string(), false));
-        
-        self.items = items;
-        // Set the first item (..) as selected by default
+        items.insert(0, FileItem::new("..".to_string(), false, 0, SystemTime::UNIX_EPOCH));
+
+        // Start the new listing on the first item (..).
         self.state.select(Some(0));
+        self.all_items = items;
+        self.apply_sort();
+        self.apply_filter();
+        // Indices are no longer stable once the listing changes.
+        self.marked.clear();
+    }
+
+    /// Cycles to the next sort mode and re-applies it to the listing.
+    fn cycle_sort(&mut self) {
+        self.sort_mode = self.sort_mode.next();
+        self.apply_sort();
+        self.apply_filter();
+    }
+
+    /// Orders `all_items` by `sort_mode`, always keeping ".." pinned first.
+    fn apply_sort(&mut self) {
+        let (dotdot, mut rest): (Vec<FileItem>, Vec<FileItem>) =
+            self.all_items.drain(..).partition(|item| item.name() == "..");
+        match self.sort_mode {
+            SortMode::Name => {
+                rest.sort_by_key(|item| item.name().to_lowercase());
+            }
+            SortMode::Size => rest.sort_by_key(|item| std::cmp::Reverse(item.size())),
+            SortMode::Mtime => rest.sort_by_key(|item| std::cmp::Reverse(item.modified())),
+            SortMode::Extension => rest.sort_by(|a, b| a.extension().cmp(b.extension())),
+            SortMode::DirsFirst => rest.sort_by(|a, b| {
+                b.is_directory()
+                    .cmp(&a.is_directory())
+                    .then_with(|| a.name().to_lowercase().cmp(&b.name().to_lowercase()))
+            }),
+        }
+        self.all_items = dotdot.into_iter().chain(rest).collect();
+    }
+
+    /// Replaces the filter substring and re-applies it.
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+        self.apply_filter();
+    }
+
+    fn clear_filter(&mut self) {
+        self.filter.clear();
+        self.apply_filter();
+    }
+
+    /// Narrows `items` to entries whose name contains `filter`
+    /// (case-insensitive), keeping the selection on the same named entry
+    /// (like `refresh_items_preserving_selection`) rather than always
+    /// jumping to the top, falling back to the first entry if it's gone.
+    fn apply_filter(&mut self) {
+        let selected_name = self
+            .state
+            .selected()
+            .and_then(|i| self.items.get(i))
+            .map(|item| item.name().to_string());
+
+        self.items = if self.filter.is_empty() {
+            self.all_items.clone()
+        } else {
+            let needle = self.filter.to_lowercase();
+            self.all_items
+                .iter()
+                .filter(|item| item.name() == ".." || item.name().to_lowercase().contains(&needle))
+                .cloned()
+                .collect()
+        };
+
+        let restored = selected_name.and_then(|name| self.items.iter().position(|i| i.name() == name));
+        self.state
+            .select(restored.or(if self.items.is_empty() { None } else { Some(0) }));
+        // `items` was just rebuilt, so any previously marked indices no
+        // longer point at the same entries.
+        self.marked.clear();
+    }
+
+    /// Toggles the mark on the entry under the cursor; ".." can't be marked.
+    fn toggle_mark(&mut self) {
+        if let Some(selected) = self.state.selected() {
+            if matches!(self.items.get(selected), Some(item) if item.name() == "..") {
+                return;
+            }
+            if !self.marked.remove(&selected) {
+                self.marked.insert(selected);
+            }
+        }
+    }
+
+    /// Marked entries (sorted by index), or the cursor entry if nothing is
+    /// marked, as `(name, absolute path)` pairs. Selecting ".." with nothing
+    /// marked copies the whole current directory.
+    fn marked_or_selected_sources(&self) -> Vec<(String, PathBuf)> {
+        if !self.marked.is_empty() {
+            let mut indices: Vec<usize> = self.marked.iter().copied().collect();
+            indices.sort_unstable();
+            return indices
+                .into_iter()
+                .filter_map(|i| self.items.get(i))
+                .filter(|item| item.name() != "..")
+                .map(|item| (item.name().to_string(), self.current_path.join(item.name())))
+                .collect();
+        }
+
+        let Some(selected) = self.state.selected() else {
+            return Vec::new();
+        };
+        match self.items.get(selected) {
+            Some(item) if item.name() == ".." => {
+                let name = self
+                    .current_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| self.current_path.display().to_string());
+                vec![(name, self.current_path.clone())]
+            }
+            Some(item) => vec![(item.name().to_string(), self.current_path.join(item.name()))],
+            None => Vec::new(),
+        }
+    }
+
+    /// Destination directory implied by the pane's current selection: the
+    /// selected subdirectory if there is one, otherwise the current path.
+    fn dest_dir(&self) -> PathBuf {
+        if let Some(selected) = self.state.selected() {
+            if let Some(item) = self.items.get(selected) {
+                if item.name() == ".." {
+                    return self.current_path.clone();
+                }
+                let candidate = self.current_path.join(item.name());
+                if candidate.is_dir() {
+                    return candidate;
+                }
+            }
+        }
+        self.current_path.clone()
     }
 }
 
@@ -155,12 +772,13 @@ impl FileList {
 pub struct AppState {
     wsl_list: FileList,
     windows_list: FileList,
+    jobs: Vec<CopyJob>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
         let config = Config::default();
-        
+
         let mut wsl_items = fs::read_dir(&config.base_wsl_path)
             .unwrap_or_else(|_| fs::read_dir("/").unwrap())
             .filter_map(|entry| {
@@ -180,6 +798,7 @@ impl Default for AppState {
         Self {
             wsl_list: FileList::new(wsl_items, config.base_wsl_path),
             windows_list: FileList::new(windows_items, config.base_windows_path),
+            jobs: Vec::new(),
         }
     }
 }
@@ -191,13 +810,81 @@ pub enum Focus {
     Windows,
 }
 
-#[derive(Debug, Default)]
+/// An inline text-input mode: while active, keystrokes edit
+/// `App::input_buffer` instead of being dispatched as commands.
+#[derive(Debug, Default, PartialEq)]
+pub enum InputMode {
+    #[default]
+    None,
+    Renaming { index: usize },
+    CreatingDir,
+    FilterInput,
+    FuzzyJump { original_selection: Option<usize> },
+}
+
 pub struct App {
     exit: bool,
     state: AppState,
     focus: Focus,
     status_message: Option<String>,
     status_timer: u8,
+    show_jobs: bool,
+    job_tx: Sender<JobUpdate>,
+    job_rx: Receiver<JobUpdate>,
+    show_preview: bool,
+    preview: Option<PreviewContent>,
+    preview_path: Option<PathBuf>,
+    preview_request_id: u64,
+    preview_cache: HashMap<PathBuf, PreviewContent>,
+    preview_tx: Sender<(u64, PathBuf, PreviewContent)>,
+    preview_rx: Receiver<(u64, PathBuf, PreviewContent)>,
+    bookmarks: Bookmarks,
+    awaiting_bookmark_key: bool,
+    show_bookmarks_popup: bool,
+    input_mode: InputMode,
+    input_buffer: String,
+}
+
+impl fmt::Debug for App {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("App")
+            .field("exit", &self.exit)
+            .field("state", &self.state)
+            .field("focus", &self.focus)
+            .field("status_message", &self.status_message)
+            .field("status_timer", &self.status_timer)
+            .field("show_jobs", &self.show_jobs)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        let (job_tx, job_rx) = mpsc::channel();
+        let (preview_tx, preview_rx) = mpsc::channel();
+        Self {
+            exit: false,
+            state: AppState::default(),
+            focus: Focus::default(),
+            status_message: None,
+            status_timer: 0,
+            show_jobs: false,
+            job_tx,
+            job_rx,
+            show_preview: false,
+            preview: None,
+            preview_path: None,
+            preview_request_id: 0,
+            preview_cache: HashMap::new(),
+            preview_tx,
+            preview_rx,
+            bookmarks: Bookmarks::load(),
+            awaiting_bookmark_key: false,
+            show_bookmarks_popup: false,
+            input_mode: InputMode::None,
+            input_buffer: String::new(),
+        }
+    }
 }
 
 impl App {
@@ -206,17 +893,107 @@ impl App {
         while !self.exit {
             terminal.draw(|frame| self.draw(frame))?;
             self.handle_events()?;
+            self.drain_job_updates();
+            self.state.wsl_list.poll_watch();
+            self.state.windows_list.poll_watch();
+            self.sync_preview();
+            self.drain_preview_updates();
             self.clear_status();
         }
         Ok(())
     }
 
+    /// Applies any progress reported by copy worker threads since the last tick.
+    fn drain_job_updates(&mut self) {
+        while let Ok(update) = self.job_rx.try_recv() {
+            match update {
+                JobUpdate::Total(id, total) => {
+                    if let Some(job) = self.state.jobs.get_mut(id) {
+                        job.total_bytes = total;
+                        job.status = CopyStatus::Running;
+                    }
+                }
+                JobUpdate::Progress(id, bytes) => {
+                    if let Some(job) = self.state.jobs.get_mut(id) {
+                        job.bytes_copied += bytes;
+                    }
+                }
+                JobUpdate::Done(id) => {
+                    if let Some(job) = self.state.jobs.get_mut(id) {
+                        job.status = CopyStatus::Done;
+                        job.bytes_copied = job.total_bytes;
+                    }
+                }
+                JobUpdate::Failed(id, err) => {
+                    if let Some(job) = self.state.jobs.get_mut(id) {
+                        job.status = CopyStatus::Failed(err);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Path of the currently focused pane's selected entry, resolving `..`
+    /// to the parent directory.
+    fn focused_path(&self) -> Option<PathBuf> {
+        let list = self.focused_list();
+        let selected = list.state.selected()?;
+        let item = list.items.get(selected)?;
+        if item.name() == ".." {
+            Some(list.current_path.parent().unwrap_or(&list.current_path).to_path_buf())
+        } else {
+            Some(list.current_path.join(item.name()))
+        }
+    }
+
+    /// Kicks off a preview load when the focused entry has changed, serving
+    /// from `preview_cache` when possible so scrolling doesn't re-read disk.
+    fn sync_preview(&mut self) {
+        if !self.show_preview {
+            return;
+        }
+        let path = self.focused_path();
+        if path == self.preview_path {
+            return;
+        }
+        self.preview_path = path.clone();
+        match path {
+            Some(p) => {
+                if let Some(cached) = self.preview_cache.get(&p) {
+                    self.preview = Some(cached.clone());
+                } else {
+                    self.preview = Some(PreviewContent::Loading);
+                    self.preview_request_id += 1;
+                    spawn_preview_job(self.preview_request_id, p, self.preview_tx.clone());
+                }
+            }
+            None => self.preview = None,
+        }
+    }
+
+    /// Applies preview results as they arrive, caching every result and only
+    /// updating the visible preview if it still matches the focused path.
+    fn drain_preview_updates(&mut self) {
+        while let Ok((id, path, content)) = self.preview_rx.try_recv() {
+            self.preview_cache.insert(path.clone(), content.clone());
+            if id == self.preview_request_id && self.preview_path.as_ref() == Some(&path) {
+                self.preview = Some(content);
+            }
+        }
+    }
+
     fn draw(&mut self, frame: &mut Frame) {
         frame.render_widget(self, frame.area());
     }
 
     /// updates the application's state based on user input
     fn handle_events(&mut self) -> io::Result<()> {
+        // Poll with a short timeout rather than blocking on `read`, so the
+        // tick loop keeps running (and watch debounces keep firing) even
+        // while the user isn't pressing keys.
+        if !event::poll(Duration::from_millis(100))? {
+            return Ok(());
+        }
         match event::read()? {
             Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
                 self.handle_key_event(key_event)
@@ -227,6 +1004,47 @@ impl App {
     }
 
     fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if self.awaiting_bookmark_key {
+            self.awaiting_bookmark_key = false;
+            if let KeyCode::Char(c) = key_event.code {
+                let path = self.focused_current_path();
+                self.bookmarks.set(c, path);
+                self.show_status(&format!("Bookmarked '{c}'"));
+            }
+            return;
+        }
+
+        if self.show_bookmarks_popup {
+            match key_event.code {
+                KeyCode::Esc => self.show_bookmarks_popup = false,
+                KeyCode::Char(c) => {
+                    if let Some(path) = self.bookmarks.get(c).cloned() {
+                        self.jump_focused_to(path);
+                    }
+                    self.show_bookmarks_popup = false;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        if self.input_mode != InputMode::None {
+            match key_event.code {
+                KeyCode::Esc => self.cancel_input(),
+                KeyCode::Enter => self.confirm_input(),
+                KeyCode::Backspace => {
+                    self.input_buffer.pop();
+                    self.on_input_changed();
+                }
+                KeyCode::Char(c) => {
+                    self.input_buffer.push(c);
+                    self.on_input_changed();
+                }
+                _ => {}
+            }
+            return;
+        }
+
         match key_event.code {
             KeyCode::Char('q') => self.exit(),
             KeyCode::Tab => self.switch_focus(),
@@ -236,10 +1054,189 @@ impl App {
             KeyCode::Char('h') | KeyCode::Left => self.navigate_up(),
             KeyCode::Char('e') => self.export_file(),
             KeyCode::Char('i') => self.import_file(),
+            KeyCode::Char('p') => self.show_jobs = !self.show_jobs,
+            KeyCode::Char('v') => self.show_preview = !self.show_preview,
+            KeyCode::Char('b') => self.awaiting_bookmark_key = true,
+            KeyCode::Char('B') => self.show_bookmarks_popup = true,
+            KeyCode::Char(' ') => self.focused_list_mut().toggle_mark(),
+            KeyCode::Char('d') => self.delete_marked(),
+            KeyCode::Char('r') => self.begin_rename(),
+            KeyCode::Char('n') => self.begin_mkdir(),
+            KeyCode::Char('s') => self.focused_list_mut().cycle_sort(),
+            KeyCode::Char('/') => self.begin_filter(),
+            KeyCode::Char('f') => self.begin_fuzzy_jump(),
             _ => {}
         }
     }
 
+    /// Starts an inline rename of the cursor entry (".." can't be renamed).
+    fn begin_rename(&mut self) {
+        let list = self.focused_list();
+        let Some(selected) = list.state.selected() else {
+            return;
+        };
+        let Some(item) = list.items.get(selected) else {
+            return;
+        };
+        if item.name() == ".." {
+            return;
+        }
+        self.input_buffer = item.name().to_string();
+        self.input_mode = InputMode::Renaming { index: selected };
+    }
+
+    /// Starts the create-directory prompt for the focused pane.
+    fn begin_mkdir(&mut self) {
+        self.input_buffer.clear();
+        self.input_mode = InputMode::CreatingDir;
+    }
+
+    fn cancel_input(&mut self) {
+        let mode = std::mem::replace(&mut self.input_mode, InputMode::None);
+        self.input_buffer.clear();
+        match mode {
+            InputMode::FilterInput => self.focused_list_mut().clear_filter(),
+            InputMode::FuzzyJump { original_selection } => {
+                self.focused_list_mut().state.select(original_selection);
+            }
+            InputMode::Renaming { .. } | InputMode::CreatingDir | InputMode::None => {}
+        }
+    }
+
+    fn confirm_input(&mut self) {
+        let name = self.input_buffer.trim().to_string();
+        let mode = std::mem::replace(&mut self.input_mode, InputMode::None);
+        self.input_buffer.clear();
+        match mode {
+            InputMode::Renaming { index } if !name.is_empty() => self.rename_item(index, name),
+            InputMode::CreatingDir if !name.is_empty() => self.create_dir(name),
+            _ => {}
+        }
+    }
+
+    /// Applies a live update to the active text-input mode after every
+    /// keystroke (filtering and fuzzy-jump act incrementally; rename and
+    /// mkdir only act once confirmed).
+    fn on_input_changed(&mut self) {
+        match self.input_mode {
+            InputMode::FilterInput => {
+                let filter = self.input_buffer.clone();
+                self.focused_list_mut().set_filter(filter);
+            }
+            InputMode::FuzzyJump { .. } => self.apply_fuzzy_jump(),
+            InputMode::Renaming { .. } | InputMode::CreatingDir | InputMode::None => {}
+        }
+    }
+
+    /// Enters incremental substring filtering of the focused pane.
+    fn begin_filter(&mut self) {
+        self.input_buffer = self.focused_list().filter.clone();
+        self.input_mode = InputMode::FilterInput;
+    }
+
+    /// Enters fuzzy-jump mode: typed characters move the selection to the
+    /// best-matching entry without otherwise changing the listing.
+    fn begin_fuzzy_jump(&mut self) {
+        let original_selection = self.focused_list().state.selected();
+        self.input_buffer.clear();
+        self.input_mode = InputMode::FuzzyJump { original_selection };
+    }
+
+    fn apply_fuzzy_jump(&mut self) {
+        let query = self.input_buffer.clone();
+        let list = self.focused_list_mut();
+        let best = list
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| fuzzy_score(item.name(), &query).map(|score| (score, i)))
+            .min_by_key(|(score, _)| *score);
+        if let Some((_, idx)) = best {
+            list.state.select(Some(idx));
+        }
+    }
+
+    fn rename_item(&mut self, index: usize, new_name: String) {
+        let list = self.focused_list();
+        let Some(item) = list.items.get(index) else {
+            return;
+        };
+        if new_name == item.name() {
+            return;
+        }
+        let old_path = list.current_path.join(item.name());
+        let new_path = list.current_path.join(&new_name);
+
+        // `fs::rename` silently replaces an existing target on POSIX, so
+        // refuse rather than clobber another entry with no way back.
+        if new_path.exists() {
+            self.show_status(&format!("{new_name} already exists"));
+            return;
+        }
+
+        let list = self.focused_list_mut();
+        match fs::rename(&old_path, &new_path) {
+            Ok(()) => {
+                list.refresh_items();
+                if let Some(idx) = list.items.iter().position(|i| i.name() == new_name) {
+                    list.state.select(Some(idx));
+                }
+                list.rewatch();
+                self.show_status(&format!("Renamed to {new_name}"));
+            }
+            Err(e) => self.show_status(&format!("Rename failed: {e}")),
+        }
+    }
+
+    fn create_dir(&mut self, name: String) {
+        let list = self.focused_list_mut();
+        let new_path = list.current_path.join(&name);
+        match fs::create_dir(&new_path) {
+            Ok(()) => {
+                list.refresh_items();
+                if let Some(idx) = list.items.iter().position(|i| i.name() == name) {
+                    list.state.select(Some(idx));
+                }
+                list.rewatch();
+                self.show_status(&format!("Created {name}"));
+            }
+            Err(e) => self.show_status(&format!("mkdir failed: {e}")),
+        }
+    }
+
+    /// Deletes the marked entries (or the cursor entry) through the `trash`
+    /// crate, so removals are recoverable rather than a hard `rm`.
+    fn delete_marked(&mut self) {
+        let sources = self.focused_list().marked_or_selected_sources();
+        if sources.is_empty() {
+            return;
+        }
+        let count = sources.len();
+        let paths: Vec<PathBuf> = sources.into_iter().map(|(_, path)| path).collect();
+
+        match trash::delete_all(paths) {
+            Ok(()) => self.show_status(&format!("Moved {count} item(s) to trash")),
+            Err(e) => self.show_status(&format!("Delete failed: {e}")),
+        }
+
+        let list = self.focused_list_mut();
+        list.refresh_items();
+        list.rewatch();
+    }
+
+    /// Current path of the focused pane, the target of `b<key>` bookmarking.
+    fn focused_current_path(&self) -> PathBuf {
+        self.focused_list().current_path().clone()
+    }
+
+    /// Jumps the focused pane to `path`, refreshing its listing and watch.
+    fn jump_focused_to(&mut self, path: PathBuf) {
+        let list = self.focused_list_mut();
+        list.current_path = path;
+        list.refresh_items();
+        list.rewatch();
+    }
+
     fn switch_focus(&mut self) {
         self.focus = match self.focus {
             Focus::Wsl => Focus::Windows,
@@ -247,32 +1244,35 @@ impl App {
         };
     }
 
-    fn select_next(&mut self) {
+    /// The `FileList` the user is currently acting on.
+    fn focused_list(&self) -> &FileList {
         match self.focus {
-            Focus::Wsl => self.state.wsl_list.select_next(),
-            Focus::Windows => self.state.windows_list.select_next(),
+            Focus::Wsl => &self.state.wsl_list,
+            Focus::Windows => &self.state.windows_list,
         }
     }
 
-    fn select_previous(&mut self) {
+    fn focused_list_mut(&mut self) -> &mut FileList {
         match self.focus {
-            Focus::Wsl => self.state.wsl_list.select_previous(),
-            Focus::Windows => self.state.windows_list.select_previous(),
+            Focus::Wsl => &mut self.state.wsl_list,
+            Focus::Windows => &mut self.state.windows_list,
         }
     }
 
+    fn select_next(&mut self) {
+        self.focused_list_mut().select_next();
+    }
+
+    fn select_previous(&mut self) {
+        self.focused_list_mut().select_previous();
+    }
+
     fn navigate_into(&mut self) {
-        match self.focus {
-            Focus::Wsl => { self.state.wsl_list.navigate_into(); }
-            Focus::Windows => { self.state.windows_list.navigate_into(); }
-        }
+        self.focused_list_mut().navigate_into();
     }
 
     fn navigate_up(&mut self) {
-        match self.focus {
-            Focus::Wsl => { self.state.wsl_list.navigate_up(); }
-            Focus::Windows => { self.state.windows_list.navigate_up(); }
-        }
+        self.focused_list_mut().navigate_up();
     }
 
     fn exit(&mut self) {
@@ -281,64 +1281,32 @@ impl App {
 
     fn export_file(&mut self) {
         // Export from WSL to Windows (always)
-        if let Some(selected) = self.state.wsl_list.state.selected() {
-            if let Some(item) = self.state.wsl_list.items().get(selected) {
-                let source_path = if item.name() == ".." {
-                    self.state.wsl_list.current_path.clone()
-                } else {
-                    self.state.wsl_list.current_path.join(item.name())
-                };
-                
-                // Use the selected directory on Windows side as destination
-                let dest_path = if let Some(windows_selected) = self.state.windows_list.state.selected() {
-                    if let Some(windows_item) = self.state.windows_list.items().get(windows_selected) {
-                        if windows_item.name() == ".." {
-                            self.state.windows_list.current_path.join(item.name())
-                        } else {
-                            self.state.windows_list.current_path.join(windows_item.name()).join(item.name())
-                        }
-                    } else {
-                        self.state.windows_list.current_path.join(item.name())
-                    }
-                } else {
-                    self.state.windows_list.current_path.join(item.name())
-                };
-                
-                self.copy_item(&source_path, &dest_path);
-                self.show_status(&format!("Exported {} to Windows", item.name()));
-            }
+        let sources = self.state.wsl_list.marked_or_selected_sources();
+        if sources.is_empty() {
+            return;
         }
+        let dest_dir = self.state.windows_list.dest_dir();
+        let count = sources.len();
+        for (name, source_path) in sources {
+            self.copy_item(source_path, dest_dir.join(name));
+        }
+        self.state.wsl_list.marked.clear();
+        self.show_status(&format!("Queued export of {count} item(s)"));
     }
 
     fn import_file(&mut self) {
         // Import from Windows to WSL (always)
-        if let Some(selected) = self.state.windows_list.state.selected() {
-            if let Some(item) = self.state.windows_list.items().get(selected) {
-                let source_path = if item.name() == ".." {
-                    self.state.windows_list.current_path.clone()
-                } else {
-                    self.state.windows_list.current_path.join(item.name())
-                };
-                
-                // Use the selected directory on WSL side as destination
-                let dest_path = if let Some(wsl_selected) = self.state.wsl_list.state.selected() {
-                    if let Some(wsl_item) = self.state.wsl_list.items().get(wsl_selected) {
-                        if wsl_item.name() == ".." {
-                            self.state.wsl_list.current_path.join(item.name())
-                        } else {
-                            self.state.wsl_list.current_path.join(wsl_item.name()).join(item.name())
-                        }
-                    } else {
-                        self.state.wsl_list.current_path.join(item.name())
-                    }
-                } else {
-                    self.state.wsl_list.current_path.join(item.name())
-                };
-                
-                self.copy_item(&source_path, &dest_path);
-                self.show_status(&format!("Imported {} to WSL", item.name()));
-            }
+        let sources = self.state.windows_list.marked_or_selected_sources();
+        if sources.is_empty() {
+            return;
+        }
+        let dest_dir = self.state.wsl_list.dest_dir();
+        let count = sources.len();
+        for (name, source_path) in sources {
+            self.copy_item(source_path, dest_dir.join(name));
         }
+        self.state.windows_list.marked.clear();
+        self.show_status(&format!("Queued import of {count} item(s)"));
     }
 
     fn show_status(&mut self, message: &str) {
@@ -354,28 +1322,13 @@ impl App {
         }
     }
 
-    fn copy_item(&self, source: &PathBuf, dest: &PathBuf) {
-        let source_str = source.to_string_lossy().to_string();
-        let dest_str = dest.to_string_lossy().to_string();
-        
-        // Use cp -r for recursive copying (works for both files and directories)
-        let output = Command::new("cp")
-            .arg("-r")
-            .arg(&source_str)
-            .arg(&dest_str)
-            .output();
-            
-        match output {
-            Ok(result) => {
-                if !result.status.success() {
-                    let error = String::from_utf8_lossy(&result.stderr);
-                    eprintln!("Failed to copy {} to {}: {}", source.display(), dest.display(), error);
-                }
-            }
-            Err(e) => {
-                eprintln!("Failed to execute cp command: {}", e);
-            }
-        }
+    /// Queues a background copy of `source` to `dest` and spawns the worker
+    /// thread that walks the tree and reports progress.
+    fn copy_item(&mut self, source: PathBuf, dest: PathBuf) {
+        let job_id = self.state.jobs.len();
+        self.state.jobs.push(CopyJob::new(source.clone(), dest.clone()));
+        spawn_copy_job(job_id, source, dest, self.job_tx.clone());
+        self.show_jobs = true;
     }
 }
 
@@ -402,9 +1355,16 @@ impl Widget for &mut App {
         //     .title_bottom(instructions.centered())
         //     .border_set(border::THICK);
 
-        let [content_area, status_area] = Layout::vertical([
+        let jobs_height = if self.show_jobs {
+            Constraint::Length((self.state.jobs.len() as u16 + 2).clamp(3, 10))
+        } else {
+            Constraint::Length(0)
+        };
+
+        let [content_area, jobs_area, status_area] = Layout::vertical([
             // Constraint::Length(3),
             Constraint::Fill(1),
+            jobs_height,
             Constraint::Length(1),
         ]).areas(area);
 
@@ -413,30 +1373,84 @@ impl Widget for &mut App {
         //     .block(block)
         //     .render(header_area, buf);
 
-        // Render two columns
-        let [wsl_area, windows_area] = Layout::horizontal([
-            Constraint::Fill(1),
-            Constraint::Fill(1),
-        ]).areas(content_area);
+        // Render two (or three, with preview) columns
+        if self.show_preview {
+            let [wsl_area, windows_area, preview_area] = Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ]).areas(content_area);
+
+            self.render_wsl_list(wsl_area, buf);
+            self.render_windows_list(windows_area, buf);
+            self.render_preview_pane(preview_area, buf);
+        } else {
+            let [wsl_area, windows_area] = Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ]).areas(content_area);
+
+            self.render_wsl_list(wsl_area, buf);
+            self.render_windows_list(windows_area, buf);
+        }
 
-        self.render_wsl_list(wsl_area, buf);
-        self.render_windows_list(windows_area, buf);
+        if self.show_jobs {
+            self.render_jobs_pane(jobs_area, buf);
+        }
+
+        // Render the status line: a live filter/fuzzy-jump prompt takes
+        // priority over the status message so the list stays visible.
+        match &self.input_mode {
+            InputMode::FilterInput => {
+                Paragraph::new(format!("/{}", self.input_buffer))
+                    .style(Style::new().fg(Color::Yellow))
+                    .render(status_area, buf);
+            }
+            InputMode::FuzzyJump { .. } => {
+                Paragraph::new(format!("find: {}", self.input_buffer))
+                    .style(Style::new().fg(Color::Yellow))
+                    .render(status_area, buf);
+            }
+            _ => {
+                if let Some(status) = &self.status_message {
+                    Paragraph::new(status.clone())
+                        .style(Style::new().fg(Color::Green))
+                        .centered()
+                        .render(status_area, buf);
+                }
+            }
+        }
+
+        if self.show_bookmarks_popup {
+            self.render_bookmarks_popup(area, buf);
+        }
 
-        // Render status message
-        if let Some(status) = &self.status_message {
-            Paragraph::new(status.clone())
-                .style(Style::new().fg(Color::Green))
-                .centered()
-                .render(status_area, buf);
+        if matches!(self.input_mode, InputMode::Renaming { .. } | InputMode::CreatingDir) {
+            self.render_input_popup(area, buf);
         }
     }
 }
 
+/// Carves a centered rectangle out of `area`, `percent_x`/`percent_y` wide/tall.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let [_, vertical, _] = Layout::vertical([
+        Constraint::Percentage((100 - percent_y) / 2),
+        Constraint::Percentage(percent_y),
+        Constraint::Percentage((100 - percent_y) / 2),
+    ]).areas(area);
+    let [_, horizontal, _] = Layout::horizontal([
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+    ]).areas(vertical);
+    horizontal
+}
+
 impl App {
     fn render_wsl_list(&mut self, area: Rect, buf: &mut Buffer) {
         let is_focused = matches!(self.focus, Focus::Wsl);
         let current_path = self.state.wsl_list.current_path().display().to_string();
-        let title = format!(" WSL: {} ", current_path).bold();
+        let title = format!(" WSL: {} [{}] ", current_path, self.state.wsl_list.sort_mode.label()).bold();
         
         let mut block = Block::bordered()
             .title(Line::from(title))
@@ -449,13 +1463,18 @@ impl App {
 
         let items: Vec<ListItem> = self.state.wsl_list.items()
             .iter()
-            .map(|item| {
-                let style = if item.is_directory() {
+            .enumerate()
+            .map(|(i, item)| {
+                let marked = self.state.wsl_list.marked.contains(&i);
+                let style = if marked {
+                    Style::new().fg(Color::Magenta)
+                } else if item.is_directory() {
                     Style::new().fg(Color::Blue)
                 } else {
                     Style::new().fg(Color::White)
                 };
-                ListItem::new(item.name().to_string()).style(style)
+                let prefix = if marked { "* " } else { "  " };
+                ListItem::new(format!("{prefix}{}", item.name())).style(style)
             })
             .collect();
 
@@ -470,7 +1489,7 @@ impl App {
     fn render_windows_list(&mut self, area: Rect, buf: &mut Buffer) {
         let is_focused = matches!(self.focus, Focus::Windows);
         let current_path = self.state.windows_list.current_path().display().to_string();
-        let title = format!(" Windows: {} ", current_path).bold();
+        let title = format!(" Windows: {} [{}] ", current_path, self.state.windows_list.sort_mode.label()).bold();
         
         let mut block = Block::bordered()
             .title(Line::from(title))
@@ -483,13 +1502,18 @@ impl App {
 
         let items: Vec<ListItem> = self.state.windows_list.items()
             .iter()
-            .map(|item| {
-                let style = if item.is_directory() {
+            .enumerate()
+            .map(|(i, item)| {
+                let marked = self.state.windows_list.marked.contains(&i);
+                let style = if marked {
+                    Style::new().fg(Color::Magenta)
+                } else if item.is_directory() {
                     Style::new().fg(Color::Blue)
                 } else {
                     Style::new().fg(Color::White)
                 };
-                ListItem::new(item.name().to_string()).style(style)
+                let prefix = if marked { "* " } else { "  " };
+                ListItem::new(format!("{prefix}{}", item.name())).style(style)
             })
             .collect();
 
@@ -500,4 +1524,121 @@ impl App {
 
         StatefulWidget::render(list, area, buf, self.state.windows_list.state_mut());
     }
+
+    /// Renders the collapsible jobs pane, one progress bar per copy job.
+    fn render_jobs_pane(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered()
+            .title(Line::from(" Jobs ".bold()))
+            .border_set(border::THICK);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if self.state.jobs.is_empty() {
+            Paragraph::new("No copy jobs yet")
+                .style(Style::new().fg(Color::DarkGray))
+                .render(inner, buf);
+            return;
+        }
+
+        let rows = Layout::vertical(
+            self.state.jobs.iter().map(|_| Constraint::Length(1)).collect::<Vec<_>>(),
+        )
+        .split(inner);
+
+        for (job, row) in self.state.jobs.iter().zip(rows.iter()) {
+            let color = match job.status {
+                CopyStatus::Queued => Color::DarkGray,
+                CopyStatus::Running => Color::Yellow,
+                CopyStatus::Done => Color::Green,
+                CopyStatus::Failed(_) => Color::Red,
+            };
+            let gauge = Gauge::default()
+                .gauge_style(Style::new().fg(color))
+                .ratio(job.progress_ratio())
+                .label(job.label());
+            gauge.render(*row, buf);
+        }
+    }
+
+    /// Renders the toggleable preview pane for the focused pane's selected entry.
+    fn render_preview_pane(&mut self, area: Rect, buf: &mut Buffer) {
+        let title = match &self.preview_path {
+            Some(path) => format!(" Preview: {} ", path.display()),
+            None => " Preview ".to_string(),
+        };
+        let block = Block::bordered()
+            .title(Line::from(title.bold()))
+            .border_set(border::THICK);
+
+        match &self.preview {
+            Some(PreviewContent::Text(lines)) => {
+                Paragraph::new(lines.clone()).block(block).render(area, buf);
+            }
+            Some(PreviewContent::Directory(entries)) => {
+                Paragraph::new(entries.join("\n")).block(block).render(area, buf);
+            }
+            Some(PreviewContent::Meta { size, permissions }) => {
+                let text = format!("Size: {size} bytes\nPermissions: {permissions}");
+                Paragraph::new(text).block(block).render(area, buf);
+            }
+            Some(PreviewContent::Error(err)) => {
+                Paragraph::new(err.clone())
+                    .style(Style::new().fg(Color::Red))
+                    .block(block)
+                    .render(area, buf);
+            }
+            Some(PreviewContent::Loading) | None => {
+                Paragraph::new("Loading...")
+                    .style(Style::new().fg(Color::DarkGray))
+                    .block(block)
+                    .render(area, buf);
+            }
+        }
+    }
+
+    /// Renders the bookmark-jump popup, a key-to-path listing the user picks
+    /// from by pressing the bookmark's character.
+    fn render_bookmarks_popup(&mut self, area: Rect, buf: &mut Buffer) {
+        let popup_area = centered_rect(60, 50, area);
+        Clear.render(popup_area, buf);
+
+        let block = Block::bordered()
+            .title(Line::from(" Bookmarks ".bold()))
+            .border_set(border::THICK);
+
+        let mut entries: Vec<(&char, &PathBuf)> = self.bookmarks.iter().collect();
+        entries.sort_by_key(|(c, _)| **c);
+
+        let lines: Vec<Line> = if entries.is_empty() {
+            vec![Line::from("No bookmarks yet — press 'b' then a key to add one")]
+        } else {
+            entries
+                .into_iter()
+                .map(|(c, path)| Line::from(format!("{c}  {}", path.display())))
+                .collect()
+        };
+
+        Paragraph::new(lines).block(block).render(popup_area, buf);
+    }
+
+    /// Renders the inline rename/mkdir text-input prompt.
+    fn render_input_popup(&mut self, area: Rect, buf: &mut Buffer) {
+        let title = match self.input_mode {
+            InputMode::Renaming { .. } => " Rename (Enter confirms, Esc cancels) ",
+            InputMode::CreatingDir => " New directory (Enter confirms, Esc cancels) ",
+            InputMode::FilterInput | InputMode::FuzzyJump { .. } | InputMode::None => return,
+        };
+
+        let popup_area = centered_rect(50, 15, area);
+        Clear.render(popup_area, buf);
+
+        let block = Block::bordered()
+            .title(Line::from(title.bold()))
+            .border_set(border::THICK)
+            .border_style(Style::new().fg(Color::Green));
+
+        Paragraph::new(self.input_buffer.clone())
+            .block(block)
+            .render(popup_area, buf);
+    }
 }
\ No newline at end of file